@@ -0,0 +1,51 @@
+use anyhow::Result;
+use palette::Srgb;
+
+use crate::types::AnsiMode;
+
+/// Whether an ANSI color escape sets the foreground or background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForegroundBackground {
+    Foreground,
+    Background,
+}
+
+/// Converts a color into the ANSI escape sequence for the given color mode.
+///
+/// `AnsiMode::Ansi16` has no 16-color mapping of its own: it gets the same 256-color
+/// escape as `AnsiMode::Ansi256`.
+pub trait ToAnsiString {
+    fn to_ansi_string(&self, mode: AnsiMode, layer: ForegroundBackground) -> String;
+}
+
+impl ToAnsiString for Srgb<u8> {
+    fn to_ansi_string(&self, mode: AnsiMode, layer: ForegroundBackground) -> String {
+        let code = match layer {
+            ForegroundBackground::Foreground => 38,
+            ForegroundBackground::Background => 48,
+        };
+        match mode {
+            AnsiMode::Rgb => format!("\x1b[{code};2;{};{};{}m", self.red, self.green, self.blue),
+            AnsiMode::Ansi256 | AnsiMode::Ansi16 => {
+                format!("\x1b[{code};5;{}m", to_ansi256(*self))
+            },
+        }
+    }
+}
+
+/// Maps a 24-bit color onto the 6x6x6 ANSI 256-color cube.
+fn to_ansi256(c: Srgb<u8>) -> u8 {
+    let to_cube = |v: u8| u8::try_from(u16::from(v) * 5 / 255).expect("0..=5 fits in a u8");
+    16 + 36 * to_cube(c.red) + 6 * to_cube(c.green) + to_cube(c.blue)
+}
+
+/// Expands `&`-style markup codes (currently just `&r` for reset) into ANSI escapes.
+pub fn color(s: &str, _mode: AnsiMode) -> Result<String> {
+    Ok(s.replace("&r", "\x1b[0m"))
+}
+
+/// Expands markup codes and prints the result to stdout.
+pub fn printc(s: &str, mode: AnsiMode) -> Result<()> {
+    print!("{}", color(s, mode)?);
+    Ok(())
+}
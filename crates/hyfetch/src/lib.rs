@@ -0,0 +1,4 @@
+pub mod color_util;
+pub mod presets;
+pub mod pride_month;
+pub mod types;
@@ -0,0 +1,41 @@
+use anyhow::Result;
+use clap::Parser;
+use hyfetch::pride_month::{self, AnimationOptions};
+use hyfetch::presets::Preset;
+use hyfetch::types::AnsiMode;
+
+/// Command-line flags for the pride-month animation.
+#[derive(Parser, Debug)]
+struct Cli {
+    /// Frames rendered per second.
+    #[arg(long, default_value_t = 25)]
+    fps: u32,
+
+    /// How many columns the diagonal color bands advance by each frame.
+    #[arg(long, default_value_t = 2)]
+    speed: usize,
+
+    /// How many diagonal color bands fit across the terminal width.
+    #[arg(long, default_value_t = 9)]
+    blocks: usize,
+
+    /// Animate a single pride flag instead of concatenating every preset.
+    #[arg(long, value_parser = clap::value_parser!(Preset))]
+    preset: Option<Preset>,
+}
+
+impl From<Cli> for AnimationOptions {
+    fn from(cli: Cli) -> Self {
+        Self {
+            fps: cli.fps,
+            speed: cli.speed,
+            blocks: cli.blocks,
+            preset: cli.preset,
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    pride_month::start_animation(AnsiMode::default(), cli.into())
+}
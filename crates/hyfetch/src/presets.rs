@@ -0,0 +1,35 @@
+use palette::Srgb;
+use strum::{EnumString, VariantArray};
+
+/// A named pride flag color scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Preset {
+    Rainbow,
+    Transgender,
+    NonBinary,
+}
+
+/// The colors that make up a preset's flag, top to bottom.
+pub struct ColorProfile {
+    pub colors: Vec<Srgb<u8>>,
+}
+
+impl Preset {
+    pub fn color_profile(&self) -> ColorProfile {
+        let hexes: &[&str] = match self {
+            Self::Rainbow => &[
+                "#E50000", "#FF8D00", "#FFEE00", "#028121", "#004CFF", "#770088",
+            ],
+            Self::Transgender => &["#5BCEFA", "#F5A9B8", "#FFFFFF", "#F5A9B8", "#5BCEFA"],
+            Self::NonBinary => &["#FCF434", "#FFFFFF", "#9C59D1", "#2C2C2C"],
+        };
+
+        ColorProfile {
+            colors: hexes
+                .iter()
+                .map(|hex| hex.parse().expect("preset color hex should be valid"))
+                .collect(),
+        }
+    }
+}
@@ -1,16 +1,20 @@
 use std::io::{self, Write as _};
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
 
-use anyhow::{Context as _, Result};
+use anyhow::{ensure, Context as _, Result};
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use crossterm::{execute, queue};
 use palette::blend::Blend as _;
 use palette::{LinSrgba, Srgb, WithAlpha as _};
 use strum::VariantArray as _;
 use terminal_size::{terminal_size, Height, Width};
 
-use crate::color_util::{clear_screen, color, printc, ForegroundBackground, ToAnsiString as _};
+use crate::color_util::{printc, ForegroundBackground, ToAnsiString as _};
 use crate::presets::Preset;
 use crate::types::AnsiMode;
 
@@ -25,154 +29,379 @@ const TEXT_ASCII: &str = r"
 
 const NOTICE: &str = "Press enter to continue";
 
-#[allow(clippy::too_many_lines)]
-pub fn start_animation(color_mode: AnsiMode) -> Result<()> {
-    let key_pressed = Arc::new(AtomicBool::new(false));
-    let mut input: String = String::new();
-    // TODO: use non-blocking I/O; no need for another thread
-    let _handle = thread::spawn({
-        let key_pressed = Arc::clone(&key_pressed);
-        move || {
-            loop {
-                match io::stdin().read_line(&mut input) {
-                    Ok(0) => {
-                        // Ignore EOF
-                    },
-                    Ok(_) => {
-                        key_pressed.store(true, Ordering::Release);
-                        break;
-                    },
-                    Err(err) => {
-                        eprintln!("failed to read line from standard input: {err}");
-                    },
+/// Switches the terminal to the alternate screen buffer and hides the cursor
+/// for as long as this guard is alive, restoring both on drop (including
+/// during a panic) so the user's scrollback is never left in a broken state.
+struct AlternateScreen;
+
+impl AlternateScreen {
+    fn enter() -> Result<Self> {
+        execute!(io::stdout(), EnterAlternateScreen, Hide)
+            .context("failed to enter alternate screen")?;
+        Ok(Self)
+    }
+}
+
+impl Drop for AlternateScreen {
+    fn drop(&mut self) {
+        // Best-effort: there's nothing useful to do if this fails, and we must not panic
+        // while already unwinding.
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+    }
+}
+
+/// Puts the terminal in raw mode for as long as this guard is alive, disabling it again on
+/// drop (including during a panic) so a crash never leaves the user's shell unusable.
+struct RawMode;
+
+impl RawMode {
+    fn enable() -> Result<Self> {
+        enable_raw_mode().context("failed to enable terminal raw mode")?;
+        Ok(Self)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+    }
+}
+
+/// Geometry derived from the terminal size and the overlay dimensions, recomputed whenever
+/// the terminal is resized.
+struct Geometry {
+    w: usize,
+    h: usize,
+    block_width: usize,
+    show_text: bool,
+    text_start_x: usize,
+    text_end_x: usize,
+    text_start_y: usize,
+    text_end_y: usize,
+    show_notice: bool,
+    notice_start_x: usize,
+    notice_end_x: usize,
+    notice_y: usize,
+}
+
+impl Geometry {
+    /// Computes layout for a `w`x`h` terminal, clamping the block width and skipping the
+    /// text/notice overlay (rather than panicking) if the terminal is too small for them.
+    fn compute(w: usize, h: usize, blocks: usize, text_width: usize, text_height: usize) -> Self {
+        let block_width = (w / blocks).max(1);
+
+        let show_text = text_width <= w && text_height <= h;
+        let (text_start_x, text_end_x, text_start_y, text_end_y) = if show_text {
+            let text_start_y = h / 2 - text_height / 2;
+            let text_start_x = w / 2 - text_width / 2;
+            (
+                text_start_x,
+                text_start_x + text_width,
+                text_start_y,
+                text_start_y + text_height,
+            )
+        } else {
+            (0, 0, 0, 0)
+        };
+
+        let show_notice = NOTICE.len() < w;
+        let (notice_start_x, notice_end_x) = if show_notice {
+            (w - NOTICE.len() - 1, w - 1)
+        } else {
+            (0, 0)
+        };
+        let notice_y = h.saturating_sub(1);
+
+        Self {
+            w,
+            h,
+            block_width,
+            show_text,
+            text_start_x,
+            text_end_x,
+            text_start_y,
+            text_end_y,
+            show_notice,
+            notice_start_x,
+            notice_end_x,
+            notice_y,
+        }
+    }
+}
+
+/// A single rendered screen cell. The foreground color is constant for the whole animation, so
+/// only the glyph and background need to be tracked for diffing between frames.
+#[derive(Clone, Copy, PartialEq)]
+struct Cell {
+    ch: char,
+    bg: Srgb<u8>,
+}
+
+/// Never produced by [`render_cells`], so comparing against it always counts as "changed" and
+/// forces a full redraw of a fresh or resized buffer.
+const BLANK_CELL: Cell = Cell {
+    ch: '\0',
+    bg: Srgb::new(0, 0, 0),
+};
+
+/// Renders one animation frame into a flat `w * h` buffer of cells, in row-major order.
+fn render_cells(
+    frame: usize,
+    geometry: &Geometry,
+    colors: &[Srgb<u8>],
+    black: LinSrgba,
+    text_lines: &[&str],
+) -> Vec<Cell> {
+    let Geometry {
+        w,
+        h,
+        block_width,
+        show_text,
+        text_start_x,
+        text_end_x,
+        text_start_y,
+        text_end_y,
+        show_notice,
+        notice_start_x,
+        notice_end_x,
+        notice_y,
+    } = *geometry;
+
+    let mut cells = Vec::with_capacity(w * h);
+
+    // Loop over the height
+    for y in 0..h {
+        // The starting color for the row
+        let mut bg = colors[((frame + y) / block_width) % colors.len()];
+
+        // Loop over the width
+        for x in 0..w {
+            let idx = frame + x + y + (2.0 * (y as f64 + 0.5 * frame as f64).sin()) as usize;
+            let y_text = show_text && text_start_y <= y && y < text_end_y;
+
+            let border = 1 + usize::from(!(y == text_start_y || y == text_end_y.wrapping_sub(1)));
+            let text_border_start = text_start_x.saturating_sub(border);
+            let notice_border_start = notice_start_x.saturating_sub(1);
+
+            // If it's a switching point
+            if idx.is_multiple_of(block_width)
+                || (show_text && (x == text_border_start || x == text_end_x + border))
+                || (show_notice && (x == notice_border_start || x == notice_end_x + 1))
+            {
+                // The color at the current frame
+                let c = colors[(idx / block_width) % colors.len()];
+                bg = if (y_text && text_border_start <= x && x < text_end_x + border)
+                    || (show_notice
+                        && y == notice_y
+                        && notice_border_start <= x
+                        && x < notice_end_x + 1)
+                {
+                    let c: LinSrgba = c.with_alpha(1.0).into_linear();
+                    Srgb::<u8>::from_linear(c.overlay(black).without_alpha())
+                } else {
+                    c.into_format()
+                };
+            }
+
+            // If text should be printed, print text
+            let ch = if y_text && text_start_x <= x && x < text_end_x {
+                text_lines[y - text_start_y]
+                    .chars()
+                    .nth(x - text_start_x)
+                    .unwrap()
+            } else if show_notice && y == notice_y && notice_start_x <= x && x < notice_end_x {
+                NOTICE.chars().nth(x - notice_start_x).unwrap()
+            } else {
+                ' '
+            };
+
+            cells.push(Cell { ch, bg });
+        }
+    }
+
+    cells
+}
+
+/// Writes only the cells that changed between `front` and `back` (both `w * h`, row-major),
+/// collapsing each run of changed cells on a row into a single cursor move plus the minimal
+/// color-escape/glyph sequence, emitting a new background escape only when it actually changes.
+/// Produces output visually identical to a full redraw of `back`.
+fn render_diff(
+    front: &[Cell],
+    back: &[Cell],
+    w: usize,
+    h: usize,
+    color_mode: AnsiMode,
+    fg: Srgb<u8>,
+) -> Result<()> {
+    let mut stdout = io::stdout().lock();
+    let mut run = String::new();
+
+    run += &fg.to_ansi_string(color_mode, ForegroundBackground::Foreground);
+
+    for y in 0..h {
+        let row = y * w;
+        let mut x = 0;
+        while x < w {
+            if back[row + x] == front[row + x] {
+                x += 1;
+                continue;
+            }
+
+            queue!(stdout, MoveTo(x as u16, y as u16))
+                .context("failed to move cursor to the start of a changed run")?;
+
+            let mut last_bg = None;
+            while x < w && back[row + x] != front[row + x] {
+                let cell = back[row + x];
+                if last_bg != Some(cell.bg) {
+                    run += &cell.bg.to_ansi_string(color_mode, ForegroundBackground::Background);
+                    last_bg = Some(cell.bg);
                 }
+                run.push(cell.ch);
+                x += 1;
             }
+
+            write!(stdout, "{run}").context("failed to write changed run to stdout")?;
+            run.clear();
         }
-    });
+    }
+
+    stdout.flush().context("failed to flush stdout")?;
+
+    Ok(())
+}
+
+/// Returns whether `event` should stop the animation: Enter, Esc, `q`, or Ctrl+C.
+fn is_quit_event(event: &Event) -> bool {
+    let Event::Key(KeyEvent {
+        code, modifiers, ..
+    }) = event
+    else {
+        return false;
+    };
+
+    matches!(code, KeyCode::Enter | KeyCode::Esc | KeyCode::Char('q'))
+        || (*code == KeyCode::Char('c') && modifiers.contains(KeyModifiers::CONTROL))
+}
+
+/// Tunable parameters for the pride-month animation, normally sourced from the CLI/config.
+#[derive(Debug, Clone, Copy)]
+pub struct AnimationOptions {
+    /// Frames rendered per second.
+    pub fps: u32,
+    /// How many columns the diagonal color bands advance by each frame.
+    pub speed: usize,
+    /// How many diagonal color bands fit across the terminal width.
+    pub blocks: usize,
+    /// The single flag to animate. When `None`, every preset is concatenated into one band,
+    /// as before.
+    pub preset: Option<Preset>,
+}
+
+impl Default for AnimationOptions {
+    fn default() -> Self {
+        Self {
+            fps: 25,
+            speed: 2,
+            blocks: 9,
+            preset: None,
+        }
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+pub fn start_animation(color_mode: AnsiMode, options: AnimationOptions) -> Result<()> {
+    let AnimationOptions {
+        fps,
+        speed,
+        blocks,
+        preset,
+    } = options;
+    ensure!(fps > 0, "fps must be greater than 0");
+    ensure!(blocks > 0, "blocks must be greater than 0");
+    let frame_delay: Duration = Duration::from_secs_f64(1.0 / f64::from(fps));
+
+    let _alternate_screen = AlternateScreen::enter()?;
+    let _raw_mode = RawMode::enable()?;
 
     let text = &TEXT_ASCII[1..TEXT_ASCII.len() - 1];
     let text_lines: Vec<&str> = text.split('\n').collect();
     let text_height: usize = text_lines.len();
     let text_width: usize = text_lines[0].len();
 
-    let speed = 2;
-    let frame_delay: Duration = Duration::from_secs_f32(1.0 / 25.0);
-
     let mut frame: usize = 0;
 
     let (w, h) = terminal_size()
         .map(|(Width(w), Height(h))| (usize::from(w), usize::from(h)))
         .context("failed to get terminal size")?;
-    const BLOCKS: usize = 9;
-    let block_width: usize = w / BLOCKS;
+    let mut geometry = Geometry::compute(w, h, blocks, text_width, text_height);
 
-    let text_start_y = (h / 2) - (text_height / 2);
-    let text_end_y = text_start_y + text_height;
-    let text_start_x = (w / 2) - (text_width / 2);
-    let text_end_x = text_start_x + text_width;
-
-    let notice_start_x = w - NOTICE.len() - 1;
-    let notice_end_x = w - 1;
-    let notice_y = h - 1;
-
-    // Add every preset to colors
-    let colors: Vec<Srgb<u8>> = Preset::VARIANTS
-        .iter()
-        .flat_map(|p| p.color_profile().colors)
-        .collect();
+    // Use only the chosen preset's colors, or every preset concatenated if none was chosen
+    let colors: Vec<Srgb<u8>> = match preset {
+        Some(preset) => preset.color_profile().colors,
+        None => Preset::VARIANTS
+            .iter()
+            .flat_map(|p| p.color_profile().colors)
+            .collect(),
+    };
 
     let fg: Srgb<u8> = "#FFE09B"
         .parse()
         .expect("foreground color hex should be valid");
     let black = LinSrgba::new(0.0, 0.0, 0.0, 0.5);
 
-    let draw_frame = |frame: usize| -> Result<()> {
-        let mut buf = String::new();
-
-        // Loop over the height
-        for y in 0..h {
-            // Print the starting color
-            buf += &colors[((frame + y) / block_width) % colors.len()]
-                .to_ansi_string(color_mode, ForegroundBackground::Background);
-            buf += &fg.to_ansi_string(color_mode, ForegroundBackground::Foreground);
-
-            // Loop over the width
-            for x in 0..w {
-                let idx = frame + x + y + (2.0 * (y as f64 + 0.5 * frame as f64).sin()) as usize;
-                let y_text = text_start_y <= y && y < text_end_y;
-
-                let border = 1 + usize::from(!(y == text_start_y || y == text_end_y - 1));
-
-                // If it's a switching point
-                if idx % block_width == 0
-                    || x == text_start_x - border
-                    || x == text_end_x + border
-                    || x == notice_start_x - 1
-                    || x == notice_end_x + 1
-                {
-                    // Print the color at the current frame
-                    let c = colors[(idx / block_width) % colors.len()];
-                    if (y_text && (text_start_x - border <= x) && (x < text_end_x + border))
-                        || (y == notice_y && notice_start_x - 1 <= x && x < notice_end_x + 1)
-                    {
-                        let c: LinSrgba = c.with_alpha(1.0).into_linear();
-                        let c = Srgb::<u8>::from_linear(c.overlay(black).without_alpha());
-                        buf += &c.to_ansi_string(color_mode, ForegroundBackground::Background);
-                    } else {
-                        buf += &c
-                            .into_format()
-                            .to_ansi_string(color_mode, ForegroundBackground::Background);
-                    }
-                }
+    // The front buffer holds what's currently on screen so each frame only needs to write the
+    // cells that actually changed. It starts at 0x0 so the first frame forces a full draw.
+    let mut front: Vec<Cell> = Vec::new();
+    let mut front_w = 0;
+    let mut front_h = 0;
 
-                // If text should be printed, print text
-                if y_text && text_start_x <= x && x < text_end_x {
-                    buf.push(
-                        text_lines[y - text_start_y]
-                            .chars()
-                            .nth(x - text_start_x)
-                            .unwrap(),
-                    );
-                } else if y == notice_y && notice_start_x <= x && x < notice_end_x {
-                    buf.push(NOTICE.chars().nth(x - notice_start_x).unwrap());
-                } else {
-                    buf.push(' ');
-                }
-            }
+    let mut draw_frame = |frame: usize, geometry: &Geometry| -> Result<()> {
+        let back = render_cells(frame, geometry, &colors, black, &text_lines);
 
-            // New line if it isn't the last line
-            if y != h - 1 {
-                buf += &color("&r\n", color_mode)
-                    .expect("line separator should not contain invalid color codes");
-            }
+        // The row stride must match too, not just the total cell count: e.g. resizing from
+        // 80x24 to 96x20 keeps the same cell count but reinterpreting the old buffer at the
+        // new stride would diff against the wrong row/column entirely.
+        if front_w != geometry.w || front_h != geometry.h {
+            front = vec![BLANK_CELL; back.len()];
+            front_w = geometry.w;
+            front_h = geometry.h;
         }
-
-        write!(io::stdout(), "{buf}")
-            .and_then(|_| io::stdout().flush())
-            .context("failed to write `buf` to stdout")?;
+        render_diff(&front, &back, geometry.w, geometry.h, color_mode, fg)?;
+        front = back;
 
         Ok(())
     };
 
     loop {
-        // Clear the screen
-        clear_screen(None, color_mode, false).context("failed to clear screen")?;
-
-        draw_frame(frame)?;
+        draw_frame(frame, &geometry)?;
         frame += speed;
-        thread::sleep(frame_delay);
 
-        // TODO: handle Ctrl+C so that we can clear the screen; but we don't have a nice
-        // way to unregister the signal handler after that :'(
-        // See https://github.com/Detegr/rust-ctrlc/issues/106
-        if key_pressed.load(Ordering::Acquire) {
-            break;
+        // Zero-timeout poll so we pick up pending key events without delaying the next
+        // frame's render.
+        if event::poll(Duration::from_millis(0)).context("failed to poll for terminal events")? {
+            let event = event::read().context("failed to read terminal event")?;
+            if is_quit_event(&event) {
+                break;
+            } else if let Event::Resize(cols, rows) = event {
+                geometry = Geometry::compute(
+                    usize::from(cols),
+                    usize::from(rows),
+                    blocks,
+                    text_width,
+                    text_height,
+                );
+                draw_frame(frame, &geometry)?;
+            }
         }
+
+        thread::sleep(frame_delay);
     }
 
-    // Clear the screen
+    // Reset terminal style; leaving the alternate screen on drop takes care of clearing
+    // the animation off the user's screen.
     printc("&r", color_mode).context("failed to reset terminal style")?;
-    clear_screen(None, color_mode, false).context("failed to clear screen")?;
 
     Ok(())
 }
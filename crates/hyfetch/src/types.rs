@@ -0,0 +1,8 @@
+/// Which ANSI color capability to target when rendering colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnsiMode {
+    Ansi16,
+    #[default]
+    Ansi256,
+    Rgb,
+}